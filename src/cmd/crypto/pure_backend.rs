@@ -0,0 +1,216 @@
+// `noring` backend: a dependency-free (no `ring`) implementation built on
+// RustCrypto crates, so jwt-hack can target `wasm32-unknown-unknown` or ship
+// as a fully-static binary. Covers the same HS*/RS*/PS*/ES* surface as the
+// default backend.
+use super::{Algorithm, DecodingKey, EncodingKey};
+use hmac::{Hmac, Mac};
+use p256::ecdsa::{Signature as P256Signature, SigningKey as P256SigningKey, VerifyingKey as P256VerifyingKey};
+use p384::ecdsa::{Signature as P384Signature, SigningKey as P384SigningKey, VerifyingKey as P384VerifyingKey};
+use rsa::pkcs1::{DecodeRsaPrivateKey, DecodeRsaPublicKey};
+use rsa::pkcs1v15::{SigningKey as Pkcs1SigningKey, VerifyingKey as Pkcs1VerifyingKey};
+use rsa::pkcs8::{DecodePrivateKey, DecodePublicKey};
+use rsa::pss::{SigningKey as PssSigningKey, VerifyingKey as PssVerifyingKey};
+use rsa::signature::{RandomizedSigner, SignatureEncoding, Signer, Verifier};
+use rsa::{RsaPrivateKey, RsaPublicKey};
+use sha2::{Sha256, Sha384, Sha512};
+
+fn rsa_private_key(key: &EncodingKey) -> Result<RsaPrivateKey, String> {
+    let EncodingKey::Pem(pem) = key else {
+        return Err("RSA algorithms require a PEM private key or JWK".to_string());
+    };
+    let pem_str = std::str::from_utf8(pem).map_err(|e| format!("invalid PEM: {e}"))?;
+    RsaPrivateKey::from_pkcs8_pem(pem_str)
+        .or_else(|_| RsaPrivateKey::from_pkcs1_pem(pem_str))
+        .map_err(|e| format!("invalid RSA private key: {e}"))
+}
+
+fn rsa_public_key(key: &DecodingKey) -> Result<RsaPublicKey, String> {
+    match key {
+        DecodingKey::Pem(pem) => {
+            let pem_str = std::str::from_utf8(pem).map_err(|e| format!("invalid PEM: {e}"))?;
+            RsaPublicKey::from_public_key_pem(pem_str)
+                .or_else(|_| RsaPublicKey::from_pkcs1_pem(pem_str))
+                .or_else(|_| RsaPrivateKey::from_pkcs8_pem(pem_str).map(|k| k.to_public_key()))
+                .map_err(|e| format!("invalid RSA key: {e}"))
+        }
+        DecodingKey::RsaComponents { n, e } => {
+            use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+            use base64::Engine;
+            use rsa::BigUint;
+            let n = URL_SAFE_NO_PAD
+                .decode(n)
+                .map_err(|e| format!("invalid modulus: {e}"))?;
+            let e = URL_SAFE_NO_PAD
+                .decode(e)
+                .map_err(|e| format!("invalid exponent: {e}"))?;
+            RsaPublicKey::new(BigUint::from_bytes_be(&n), BigUint::from_bytes_be(&e))
+                .map_err(|e| format!("invalid RSA modulus/exponent: {e}"))
+        }
+        DecodingKey::Hmac(_) => Err("expected an RSA key, got an HMAC secret".to_string()),
+    }
+}
+
+fn ec_private_key_p256(key: &EncodingKey) -> Result<P256SigningKey, String> {
+    let EncodingKey::Pem(pem) = key else {
+        return Err("EC algorithms require a PEM private key".to_string());
+    };
+    let pem_str = std::str::from_utf8(pem).map_err(|e| format!("invalid PEM: {e}"))?;
+    P256SigningKey::from_pkcs8_pem(pem_str).map_err(|e| format!("invalid P-256 private key: {e}"))
+}
+
+fn ec_public_key_p256(key: &DecodingKey) -> Result<P256VerifyingKey, String> {
+    let DecodingKey::Pem(pem) = key else {
+        return Err("EC algorithms require a PEM key".to_string());
+    };
+    let pem_str = std::str::from_utf8(pem).map_err(|e| format!("invalid PEM: {e}"))?;
+    P256VerifyingKey::from_public_key_pem(pem_str)
+        .map_err(|e| format!("invalid P-256 public key: {e}"))
+}
+
+fn ec_private_key_p384(key: &EncodingKey) -> Result<P384SigningKey, String> {
+    let EncodingKey::Pem(pem) = key else {
+        return Err("EC algorithms require a PEM private key".to_string());
+    };
+    let pem_str = std::str::from_utf8(pem).map_err(|e| format!("invalid PEM: {e}"))?;
+    P384SigningKey::from_pkcs8_pem(pem_str).map_err(|e| format!("invalid P-384 private key: {e}"))
+}
+
+fn ec_public_key_p384(key: &DecodingKey) -> Result<P384VerifyingKey, String> {
+    let DecodingKey::Pem(pem) = key else {
+        return Err("EC algorithms require a PEM key".to_string());
+    };
+    let pem_str = std::str::from_utf8(pem).map_err(|e| format!("invalid PEM: {e}"))?;
+    P384VerifyingKey::from_public_key_pem(pem_str)
+        .map_err(|e| format!("invalid P-384 public key: {e}"))
+}
+
+pub(super) fn sign(alg: Algorithm, signing_input: &[u8], key: &EncodingKey) -> Result<Vec<u8>, String> {
+    match alg {
+        Algorithm::HS256 => hmac_sign::<Hmac<Sha256>>(signing_input, key),
+        Algorithm::HS384 => hmac_sign::<Hmac<Sha384>>(signing_input, key),
+        Algorithm::HS512 => hmac_sign::<Hmac<Sha512>>(signing_input, key),
+        Algorithm::RS256 => {
+            let signing_key = Pkcs1SigningKey::<Sha256>::new(rsa_private_key(key)?);
+            Ok(signing_key.sign(signing_input).to_vec())
+        }
+        Algorithm::RS384 => {
+            let signing_key = Pkcs1SigningKey::<Sha384>::new(rsa_private_key(key)?);
+            Ok(signing_key.sign(signing_input).to_vec())
+        }
+        Algorithm::RS512 => {
+            let signing_key = Pkcs1SigningKey::<Sha512>::new(rsa_private_key(key)?);
+            Ok(signing_key.sign(signing_input).to_vec())
+        }
+        Algorithm::PS256 => {
+            let signing_key = PssSigningKey::<Sha256>::new(rsa_private_key(key)?);
+            Ok(signing_key
+                .sign_with_rng(&mut rand::thread_rng(), signing_input)
+                .to_vec())
+        }
+        Algorithm::PS384 => {
+            let signing_key = PssSigningKey::<Sha384>::new(rsa_private_key(key)?);
+            Ok(signing_key
+                .sign_with_rng(&mut rand::thread_rng(), signing_input)
+                .to_vec())
+        }
+        Algorithm::PS512 => {
+            let signing_key = PssSigningKey::<Sha512>::new(rsa_private_key(key)?);
+            Ok(signing_key
+                .sign_with_rng(&mut rand::thread_rng(), signing_input)
+                .to_vec())
+        }
+        Algorithm::ES256 => {
+            let signing_key = ec_private_key_p256(key)?;
+            let signature: P256Signature = signing_key.sign(signing_input);
+            Ok(signature.to_bytes().to_vec())
+        }
+        Algorithm::ES384 => {
+            let signing_key = ec_private_key_p384(key)?;
+            let signature: P384Signature = signing_key.sign(signing_input);
+            Ok(signature.to_bytes().to_vec())
+        }
+    }
+}
+
+pub(super) fn verify(
+    alg: Algorithm,
+    signing_input: &[u8],
+    signature: &[u8],
+    key: &DecodingKey,
+) -> Result<bool, String> {
+    match alg {
+        Algorithm::HS256 => hmac_verify::<Hmac<Sha256>>(signing_input, signature, key),
+        Algorithm::HS384 => hmac_verify::<Hmac<Sha384>>(signing_input, signature, key),
+        Algorithm::HS512 => hmac_verify::<Hmac<Sha512>>(signing_input, signature, key),
+        Algorithm::RS256 => {
+            let verifying_key = Pkcs1VerifyingKey::<Sha256>::new(rsa_public_key(key)?);
+            let sig = rsa::pkcs1v15::Signature::try_from(signature)
+                .map_err(|e| format!("invalid signature: {e}"))?;
+            Ok(verifying_key.verify(signing_input, &sig).is_ok())
+        }
+        Algorithm::RS384 => {
+            let verifying_key = Pkcs1VerifyingKey::<Sha384>::new(rsa_public_key(key)?);
+            let sig = rsa::pkcs1v15::Signature::try_from(signature)
+                .map_err(|e| format!("invalid signature: {e}"))?;
+            Ok(verifying_key.verify(signing_input, &sig).is_ok())
+        }
+        Algorithm::RS512 => {
+            let verifying_key = Pkcs1VerifyingKey::<Sha512>::new(rsa_public_key(key)?);
+            let sig = rsa::pkcs1v15::Signature::try_from(signature)
+                .map_err(|e| format!("invalid signature: {e}"))?;
+            Ok(verifying_key.verify(signing_input, &sig).is_ok())
+        }
+        Algorithm::PS256 => {
+            let verifying_key = PssVerifyingKey::<Sha256>::new(rsa_public_key(key)?);
+            let sig = rsa::pss::Signature::try_from(signature)
+                .map_err(|e| format!("invalid signature: {e}"))?;
+            Ok(verifying_key.verify(signing_input, &sig).is_ok())
+        }
+        Algorithm::PS384 => {
+            let verifying_key = PssVerifyingKey::<Sha384>::new(rsa_public_key(key)?);
+            let sig = rsa::pss::Signature::try_from(signature)
+                .map_err(|e| format!("invalid signature: {e}"))?;
+            Ok(verifying_key.verify(signing_input, &sig).is_ok())
+        }
+        Algorithm::PS512 => {
+            let verifying_key = PssVerifyingKey::<Sha512>::new(rsa_public_key(key)?);
+            let sig = rsa::pss::Signature::try_from(signature)
+                .map_err(|e| format!("invalid signature: {e}"))?;
+            Ok(verifying_key.verify(signing_input, &sig).is_ok())
+        }
+        Algorithm::ES256 => {
+            let verifying_key = ec_public_key_p256(key)?;
+            let sig = P256Signature::try_from(signature).map_err(|e| format!("invalid signature: {e}"))?;
+            Ok(verifying_key.verify(signing_input, &sig).is_ok())
+        }
+        Algorithm::ES384 => {
+            let verifying_key = ec_public_key_p384(key)?;
+            let sig = P384Signature::try_from(signature).map_err(|e| format!("invalid signature: {e}"))?;
+            Ok(verifying_key.verify(signing_input, &sig).is_ok())
+        }
+    }
+}
+
+fn hmac_sign<M>(signing_input: &[u8], key: &EncodingKey) -> Result<Vec<u8>, String>
+where
+    M: Mac + hmac::digest::KeyInit,
+{
+    let EncodingKey::Hmac(secret) = key else {
+        return Err("HMAC algorithms require a secret, not a PEM key".to_string());
+    };
+    let mut mac = <M as Mac>::new_from_slice(secret).map_err(|e| format!("invalid HMAC key: {e}"))?;
+    mac.update(signing_input);
+    Ok(mac.finalize().into_bytes().to_vec())
+}
+
+fn hmac_verify<M>(signing_input: &[u8], signature: &[u8], key: &DecodingKey) -> Result<bool, String>
+where
+    M: Mac + hmac::digest::KeyInit,
+{
+    let DecodingKey::Hmac(secret) = key else {
+        return Err("HMAC algorithms require a secret, not a PEM key".to_string());
+    };
+    let mut mac = <M as Mac>::new_from_slice(secret).map_err(|e| format!("invalid HMAC key: {e}"))?;
+    mac.update(signing_input);
+    Ok(mac.verify_slice(signature).is_ok())
+}
@@ -0,0 +1,86 @@
+// Default crypto backend: delegates to `jsonwebtoken`, which relies on
+// `ring` for its cryptographic primitives.
+use super::{Algorithm, DecodingKey, EncodingKey};
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+
+fn to_jwt_alg(alg: Algorithm) -> jsonwebtoken::Algorithm {
+    match alg {
+        Algorithm::HS256 => jsonwebtoken::Algorithm::HS256,
+        Algorithm::HS384 => jsonwebtoken::Algorithm::HS384,
+        Algorithm::HS512 => jsonwebtoken::Algorithm::HS512,
+        Algorithm::RS256 => jsonwebtoken::Algorithm::RS256,
+        Algorithm::RS384 => jsonwebtoken::Algorithm::RS384,
+        Algorithm::RS512 => jsonwebtoken::Algorithm::RS512,
+        Algorithm::ES256 => jsonwebtoken::Algorithm::ES256,
+        Algorithm::ES384 => jsonwebtoken::Algorithm::ES384,
+        Algorithm::PS256 => jsonwebtoken::Algorithm::PS256,
+        Algorithm::PS384 => jsonwebtoken::Algorithm::PS384,
+        Algorithm::PS512 => jsonwebtoken::Algorithm::PS512,
+    }
+}
+
+fn jwt_encoding_key(alg: jsonwebtoken::Algorithm, key: &EncodingKey) -> Result<jsonwebtoken::EncodingKey, String> {
+    match key {
+        EncodingKey::Hmac(secret) => Ok(jsonwebtoken::EncodingKey::from_secret(secret)),
+        EncodingKey::Pem(pem) => match alg {
+            jsonwebtoken::Algorithm::RS256
+            | jsonwebtoken::Algorithm::RS384
+            | jsonwebtoken::Algorithm::RS512
+            | jsonwebtoken::Algorithm::PS256
+            | jsonwebtoken::Algorithm::PS384
+            | jsonwebtoken::Algorithm::PS512 => jsonwebtoken::EncodingKey::from_rsa_pem(pem)
+                .map_err(|e| format!("invalid RSA private key: {e}")),
+            jsonwebtoken::Algorithm::ES256 | jsonwebtoken::Algorithm::ES384 => {
+                jsonwebtoken::EncodingKey::from_ec_pem(pem)
+                    .map_err(|e| format!("invalid EC private key: {e}"))
+            }
+            _ => Err("unsupported algorithm".to_string()),
+        },
+    }
+}
+
+fn jwt_decoding_key(alg: jsonwebtoken::Algorithm, key: &DecodingKey) -> Result<jsonwebtoken::DecodingKey, String> {
+    match key {
+        DecodingKey::Hmac(secret) => Ok(jsonwebtoken::DecodingKey::from_secret(secret)),
+        DecodingKey::Pem(pem) => match alg {
+            jsonwebtoken::Algorithm::RS256
+            | jsonwebtoken::Algorithm::RS384
+            | jsonwebtoken::Algorithm::RS512
+            | jsonwebtoken::Algorithm::PS256
+            | jsonwebtoken::Algorithm::PS384
+            | jsonwebtoken::Algorithm::PS512 => {
+                jsonwebtoken::DecodingKey::from_rsa_pem(pem).map_err(|e| format!("invalid RSA key: {e}"))
+            }
+            jsonwebtoken::Algorithm::ES256 | jsonwebtoken::Algorithm::ES384 => {
+                jsonwebtoken::DecodingKey::from_ec_pem(pem).map_err(|e| format!("invalid EC key: {e}"))
+            }
+            _ => Err("unsupported algorithm".to_string()),
+        },
+        DecodingKey::RsaComponents { n, e } => jsonwebtoken::DecodingKey::from_rsa_components(n, e)
+            .map_err(|e| format!("invalid RSA modulus/exponent: {e}")),
+    }
+}
+
+pub(super) fn sign(alg: Algorithm, signing_input: &[u8], key: &EncodingKey) -> Result<Vec<u8>, String> {
+    let jwt_alg = to_jwt_alg(alg);
+    let jwt_key = jwt_encoding_key(jwt_alg, key)?;
+    let signature_b64 = jsonwebtoken::crypto::sign(signing_input, &jwt_key, jwt_alg)
+        .map_err(|e| format!("signing failed: {e}"))?;
+    URL_SAFE_NO_PAD
+        .decode(signature_b64)
+        .map_err(|e| format!("internal error decoding signature: {e}"))
+}
+
+pub(super) fn verify(
+    alg: Algorithm,
+    signing_input: &[u8],
+    signature: &[u8],
+    key: &DecodingKey,
+) -> Result<bool, String> {
+    let jwt_alg = to_jwt_alg(alg);
+    let jwt_key = jwt_decoding_key(jwt_alg, key)?;
+    let signature_b64 = URL_SAFE_NO_PAD.encode(signature);
+    jsonwebtoken::crypto::verify(&signature_b64, signing_input, &jwt_key, jwt_alg)
+        .map_err(|e| format!("verification failed: {e}"))
+}
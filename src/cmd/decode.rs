@@ -0,0 +1,45 @@
+use crate::cmd::crypto::Algorithm;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use log::{error, info};
+use serde_json::Value;
+
+/// Decodes a JWT token and prints its header and payload without verifying
+/// the signature.
+pub fn execute(token: &str) {
+    let parts: Vec<&str> = token.split('.').collect();
+    if parts.len() != 3 {
+        error!("Malformed JWT: expected header.payload.signature");
+        return;
+    }
+
+    let header = match decode_segment(parts[0]) {
+        Ok(v) => v,
+        Err(e) => {
+            error!("Invalid JWT header: {e}");
+            return;
+        }
+    };
+    let payload = match decode_segment(parts[1]) {
+        Ok(v) => v,
+        Err(e) => {
+            error!("Invalid JWT payload: {e}");
+            return;
+        }
+    };
+
+    info!("Header: {}", serde_json::to_string_pretty(&header).unwrap());
+    info!("Payload: {}", serde_json::to_string_pretty(&payload).unwrap());
+
+    match header.get("alg").and_then(Value::as_str).and_then(Algorithm::from_str) {
+        Some(alg) => info!("Algorithm: {}", alg.as_str()),
+        None => error!("Unknown or missing `alg` in header"),
+    }
+}
+
+fn decode_segment(segment: &str) -> Result<Value, String> {
+    let bytes = URL_SAFE_NO_PAD
+        .decode(segment)
+        .map_err(|e| format!("invalid base64url: {e}"))?;
+    serde_json::from_slice(&bytes).map_err(|e| format!("invalid JSON: {e}"))
+}
@@ -0,0 +1,102 @@
+use crate::cmd::algorithm::parse_algorithm;
+use crate::cmd::crypto::{self, EncodingKey};
+use crate::cmd::jwk;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use log::{error, info};
+use serde_json::{Map, Value};
+use std::fs;
+use std::path::PathBuf;
+
+/// Encodes a JSON payload into a JWT, signing it with the requested algorithm.
+#[allow(clippy::too_many_arguments)]
+pub fn execute(
+    json: &str,
+    secret: Option<&str>,
+    private_key: Option<&PathBuf>,
+    jwk_file: Option<&PathBuf>,
+    algorithm: &str,
+    no_signature: bool,
+    headers: Vec<(String, String)>,
+) {
+    let claims: Value = match serde_json::from_str(json) {
+        Ok(v) => v,
+        Err(e) => {
+            error!("Invalid JSON payload: {e}");
+            return;
+        }
+    };
+
+    if no_signature {
+        let token = encode_none(&claims, headers);
+        info!("{token}");
+        return;
+    }
+
+    let alg = match parse_algorithm(algorithm) {
+        Ok(a) => a,
+        Err(e) => {
+            error!("{e}");
+            return;
+        }
+    };
+
+    let encoding_key = match build_encoding_key(alg, secret, private_key, jwk_file) {
+        Ok(k) => k,
+        Err(e) => {
+            error!("{e}");
+            return;
+        }
+    };
+
+    let mut extra_header = Map::new();
+    for (key, value) in headers {
+        extra_header.insert(key, Value::String(value));
+    }
+
+    match crypto::encode(alg, &claims, &extra_header, &encoding_key) {
+        Ok(token) => info!("{token}"),
+        Err(e) => error!("Failed to encode JWT: {e}"),
+    }
+}
+
+/// Builds the signing key for the given algorithm from a secret, a PEM
+/// private key, or an RSA JWK document containing private components.
+///
+/// Shared with the `sdjwt` module, which signs its credential JWT the same way.
+pub(crate) fn build_encoding_key(
+    alg: crypto::Algorithm,
+    secret: Option<&str>,
+    private_key: Option<&PathBuf>,
+    jwk_file: Option<&PathBuf>,
+) -> Result<EncodingKey, String> {
+    if alg.is_hmac() {
+        let secret = secret.ok_or("algorithm requires --secret")?;
+        return Ok(EncodingKey::from_secret(secret.as_bytes()));
+    }
+
+    if let Some(path) = jwk_file {
+        let key = jwk::load(path)?;
+        let pem = jwk::build_rsa_private_pem(&key)?;
+        return Ok(EncodingKey::from_pem(pem.as_bytes()));
+    }
+
+    let path = private_key.ok_or("algorithm requires --private-key or --jwk")?;
+    let pem = fs::read(path).map_err(|e| format!("failed to read private key: {e}"))?;
+    Ok(EncodingKey::from_pem(&pem))
+}
+
+/// Builds an unsigned ("alg": "none") JWT by hand, since signing backends
+/// refuse to produce these for safety reasons.
+fn encode_none(claims: &Value, headers: Vec<(String, String)>) -> String {
+    let mut header = serde_json::json!({ "alg": "none", "typ": "JWT" });
+    if let Some(obj) = header.as_object_mut() {
+        for (key, value) in headers {
+            obj.insert(key, Value::String(value));
+        }
+    }
+
+    let header_b64 = URL_SAFE_NO_PAD.encode(header.to_string());
+    let payload_b64 = URL_SAFE_NO_PAD.encode(claims.to_string());
+    format!("{header_b64}.{payload_b64}.")
+}
@@ -0,0 +1,119 @@
+// Minimal RSA JWK (JSON Web Key) support, used to build verification/signing
+// keys from a published `jwks.json` without converting to PEM by hand.
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use rsa::pkcs8::EncodePrivateKey;
+use rsa::{BigUint, RsaPrivateKey};
+use serde::Deserialize;
+use serde_json::Value;
+use std::fs;
+use std::path::Path;
+
+/// A single RSA entry from a JWK or JWK Set document.
+#[derive(Debug, Deserialize)]
+pub struct Jwk {
+    pub kty: String,
+    pub n: String,
+    pub e: String,
+    pub d: Option<String>,
+    pub p: Option<String>,
+    pub q: Option<String>,
+}
+
+/// Loads a single RSA JWK from a file. Accepts either a bare JWK object or a
+/// JWK Set (`{"keys": [...]}`), in which case the first RSA key is used.
+pub fn load(path: &Path) -> Result<Jwk, String> {
+    let raw = fs::read_to_string(path).map_err(|e| format!("failed to read JWK file: {e}"))?;
+    parse(&raw)
+}
+
+/// Parses a JWK or JWK Set document from a JSON string.
+pub fn parse(raw: &str) -> Result<Jwk, String> {
+    let value: Value = serde_json::from_str(raw).map_err(|e| format!("invalid JWK JSON: {e}"))?;
+    let jwk_value = if let Some(keys) = value.get("keys").and_then(Value::as_array) {
+        keys.iter()
+            .find(|k| k.get("kty").and_then(Value::as_str) == Some("RSA"))
+            .cloned()
+            .ok_or("no RSA key found in JWK set")?
+    } else {
+        value
+    };
+
+    let jwk: Jwk = serde_json::from_value(jwk_value).map_err(|e| format!("invalid JWK: {e}"))?;
+    if jwk.kty != "RSA" {
+        return Err(format!("unsupported JWK key type `{}` (expected RSA)", jwk.kty));
+    }
+    Ok(jwk)
+}
+
+/// Reconstructs the RSA private key PEM from a JWK's `d`/`p`/`q` components.
+pub fn build_rsa_private_pem(jwk: &Jwk) -> Result<String, String> {
+    let n = decode_uint(&jwk.n)?;
+    let e = decode_uint(&jwk.e)?;
+    let d = jwk
+        .d
+        .as_deref()
+        .ok_or("JWK has no private exponent `d`; it cannot be used to sign")?;
+    let d = decode_uint(d)?;
+    let p = jwk
+        .p
+        .as_deref()
+        .ok_or("JWK is missing prime factor `p`")?;
+    let q = jwk
+        .q
+        .as_deref()
+        .ok_or("JWK is missing prime factor `q`")?;
+
+    let key = RsaPrivateKey::from_components(n, e, d, vec![decode_uint(p)?, decode_uint(q)?])
+        .map_err(|e| format!("invalid RSA JWK components: {e}"))?;
+    key.to_pkcs8_pem(Default::default())
+        .map(|pem| pem.to_string())
+        .map_err(|e| format!("failed to encode RSA key: {e}"))
+}
+
+fn decode_uint(b64url: &str) -> Result<BigUint, String> {
+    let bytes = URL_SAFE_NO_PAD
+        .decode(b64url)
+        .map_err(|e| format!("invalid base64url value: {e}"))?;
+    Ok(BigUint::from_bytes_be(&bytes))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_uint_handles_unpadded_base64url() {
+        // 2 raw bytes base64url-encode to 3 chars, i.e. not a multiple of 4
+        // and with no trailing `=` padding.
+        let encoded = URL_SAFE_NO_PAD.encode([0x01, 0x00]);
+        assert_eq!(encoded.len(), 3);
+        assert_eq!(decode_uint(&encoded).unwrap(), BigUint::from(256u32));
+    }
+
+    #[test]
+    fn decode_uint_round_trips_rsa_exponent() {
+        // The classic RSA public exponent 65537, minimally encoded as 3 bytes.
+        let encoded = URL_SAFE_NO_PAD.encode([0x01, 0x00, 0x01]);
+        assert_eq!(decode_uint(&encoded).unwrap(), BigUint::from(65537u32));
+    }
+
+    #[test]
+    fn decode_uint_rejects_invalid_base64url() {
+        assert!(decode_uint("not valid base64!!").is_err());
+    }
+
+    #[test]
+    fn parse_picks_first_rsa_key_from_a_jwk_set() {
+        let set = r#"{"keys":[{"kty":"EC","n":"x","e":"y"},{"kty":"RSA","n":"AQAB","e":"AQAB"}]}"#;
+        let jwk = parse(set).unwrap();
+        assert_eq!(jwk.kty, "RSA");
+        assert_eq!(jwk.n, "AQAB");
+    }
+
+    #[test]
+    fn parse_rejects_non_rsa_key() {
+        let raw = r#"{"kty":"EC","n":"x","e":"y"}"#;
+        assert!(parse(raw).is_err());
+    }
+}
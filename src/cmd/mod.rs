@@ -2,10 +2,14 @@ use clap::{Parser, Subcommand};
 use log::error;
 use std::path::PathBuf;
 
+mod algorithm;
 mod crack;
+mod crypto;
 mod decode;
 mod encode;
+mod jwk;
 mod payload;
+mod sdjwt;
 mod verify;
 mod version;
 
@@ -46,7 +50,11 @@ pub enum Commands {
         #[arg(long)]
         private_key: Option<PathBuf>,
 
-        /// Algorithm to use
+        /// RSA JWK JSON document (or JWK Set) holding the private key components
+        #[arg(long)]
+        jwk: Option<PathBuf>,
+
+        /// Algorithm to use (HS256/384/512, RS256/384/512, ES256/384, PS256/384/512)
         #[arg(long, default_value = "HS256")]
         algorithm: String,
 
@@ -72,9 +80,45 @@ pub enum Commands {
         #[arg(long)]
         private_key: Option<PathBuf>,
 
+        /// RSA JWK JSON document (or JWK Set) holding the public key (n/e)
+        #[arg(long)]
+        jwk: Option<PathBuf>,
+
+        /// RSA modulus, base64url-encoded (used with --jwk-e)
+        #[arg(long)]
+        jwk_n: Option<String>,
+
+        /// RSA public exponent, base64url-encoded (used with --jwk-n)
+        #[arg(long)]
+        jwk_e: Option<String>,
+
         /// Validate expiration claim (exp)
         #[arg(long)]
         validate_exp: bool,
+
+        /// Validate not-before claim (nbf)
+        #[arg(long)]
+        validate_nbf: bool,
+
+        /// Validate issued-at claim (iat)
+        #[arg(long)]
+        validate_iat: bool,
+
+        /// Expected audience (aud); accepted if the token's aud contains this value
+        #[arg(long)]
+        aud: Option<String>,
+
+        /// Expected issuer (iss)
+        #[arg(long)]
+        iss: Option<String>,
+
+        /// Expected subject (sub)
+        #[arg(long)]
+        sub: Option<String>,
+
+        /// Clock-skew leeway in seconds, applied to exp/nbf/iat comparisons
+        #[arg(long, default_value = "0")]
+        leeway: u64,
     },
 
     /// Attempts to crack a JWT token using dictionary or bruteforce methods
@@ -82,7 +126,7 @@ pub enum Commands {
         /// JWT token to crack
         token: String,
 
-        /// Cracking mode, you can use 'dict' or 'brute'
+        /// Cracking mode: 'dict', 'brute', or 'confusion' (RS*/ES* -> HS* algorithm confusion)
         #[arg(short, long, default_value = "dict")]
         mode: String,
 
@@ -109,6 +153,10 @@ pub enum Commands {
         /// Show testing log
         #[arg(long)]
         verbose: bool,
+
+        /// Target's PEM public key (for 'confusion' mode)
+        #[arg(long)]
+        pubkey: Option<PathBuf>,
     },
 
     /// Generates various JWT attack payloads for security testing
@@ -133,10 +181,47 @@ pub enum Commands {
         target: Option<String>,
     },
 
+    /// Produces and parses Selective-Disclosure JWTs (SD-JWT) for testing issuers/verifiers
+    SdJwt {
+        #[command(subcommand)]
+        command: SdJwtCommand,
+    },
+
     /// Displays version information and project details
     Version,
 }
 
+#[derive(Subcommand, Debug)]
+pub enum SdJwtCommand {
+    /// Encodes JSON data into an SD-JWT, making the given claims selectively disclosable
+    Encode {
+        /// JSON data to encode
+        json: String,
+
+        /// Claim name to make selectively-disclosable (repeatable)
+        #[arg(long = "disclose")]
+        disclose: Vec<String>,
+
+        /// Secret key for HMAC algorithms (HS256, HS384, HS512)
+        #[arg(long)]
+        secret: Option<String>,
+
+        /// RSA or ECDSA private key in PEM format for asymmetric algorithms
+        #[arg(long)]
+        private_key: Option<PathBuf>,
+
+        /// Algorithm to use (HS256/384/512, RS256/384/512, ES256/384, PS256/384/512)
+        #[arg(long, default_value = "HS256")]
+        algorithm: String,
+    },
+
+    /// Decodes an SD-JWT, showing which claims were disclosed
+    Decode {
+        /// SD-JWT to decode (`<jwt>~<disclosure>~...~`)
+        token: String,
+    },
+}
+
 /// Parses command-line arguments and executes the appropriate command
 pub fn execute() {
     let cli = Cli::parse();
@@ -149,6 +234,7 @@ pub fn execute() {
             json,
             secret,
             private_key,
+            jwk,
             algorithm,
             no_signature,
             header,
@@ -157,6 +243,7 @@ pub fn execute() {
                 json,
                 secret.as_deref(),
                 private_key.as_ref(),
+                jwk.as_ref(),
                 algorithm,
                 *no_signature,
                 header.clone(),
@@ -166,13 +253,31 @@ pub fn execute() {
             token,
             secret,
             private_key,
+            jwk,
+            jwk_n,
+            jwk_e,
             validate_exp,
+            validate_nbf,
+            validate_iat,
+            aud,
+            iss,
+            sub,
+            leeway,
         }) => {
             verify::execute(
                 token,
                 secret.as_deref(),
                 private_key.as_ref(),
+                jwk.as_ref(),
+                jwk_n.as_deref(),
+                jwk_e.as_deref(),
                 *validate_exp,
+                *validate_nbf,
+                *validate_iat,
+                aud.as_deref(),
+                iss.as_deref(),
+                sub.as_deref(),
+                *leeway,
             );
         }
         Some(Commands::Crack {
@@ -184,6 +289,7 @@ pub fn execute() {
             max,
             power,
             verbose,
+            pubkey,
         }) => {
             crack::execute(
                 token,
@@ -194,6 +300,7 @@ pub fn execute() {
                 *max,
                 *power,
                 *verbose,
+                pubkey.as_ref(),
             );
         }
         Some(Commands::Payload {
@@ -211,6 +318,9 @@ pub fn execute() {
                 target.as_deref(),
             );
         }
+        Some(Commands::SdJwt { command }) => {
+            sdjwt::execute(command);
+        }
         Some(Commands::Version) => {
             version::execute();
         }
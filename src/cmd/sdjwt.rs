@@ -0,0 +1,333 @@
+// Selective-Disclosure JWT (SD-JWT) support: issuing credentials with some
+// claims hidden behind a digest, and parsing them back to show what a
+// presentation actually discloses.
+use crate::cmd::algorithm::parse_algorithm;
+use crate::cmd::crypto;
+use crate::cmd::encode::build_encoding_key;
+use crate::cmd::SdJwtCommand;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use log::{error, info};
+use rand::RngCore;
+use serde_json::{json, Map, Value};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+pub fn execute(command: &SdJwtCommand) {
+    match command {
+        SdJwtCommand::Encode {
+            json,
+            disclose,
+            secret,
+            private_key,
+            algorithm,
+        } => encode(json, disclose, secret.as_deref(), private_key.as_ref(), algorithm),
+        SdJwtCommand::Decode { token } => decode(token),
+    }
+}
+
+/// Issues an SD-JWT: signs the payload with the given claims replaced by
+/// `_sd` digests, and prints `<jwt>~<disclosure>~...~`.
+fn encode(
+    json: &str,
+    disclose: &[String],
+    secret: Option<&str>,
+    private_key: Option<&PathBuf>,
+    algorithm: &str,
+) {
+    let mut claims: Value = match serde_json::from_str(json) {
+        Ok(v @ Value::Object(_)) => v,
+        Ok(_) => {
+            error!("SD-JWT payload must be a JSON object");
+            return;
+        }
+        Err(e) => {
+            error!("Invalid JSON payload: {e}");
+            return;
+        }
+    };
+    let obj = claims.as_object_mut().expect("checked above");
+
+    let mut rng = rand::thread_rng();
+    let mut disclosures = Vec::new();
+    let mut digests = Vec::new();
+
+    for name in disclose {
+        let Some(value) = obj.remove(name) else {
+            error!("Claim `{name}` not present in payload, skipping");
+            continue;
+        };
+        let (disclosure, digest) = make_disclosure(&mut rng, name, &value);
+        info!("Disclosure for `{name}`: {disclosure}");
+        disclosures.push(disclosure);
+        digests.push(Value::String(digest));
+    }
+
+    if !digests.is_empty() {
+        obj.insert("_sd".to_string(), Value::Array(digests));
+        obj.insert("_sd_alg".to_string(), Value::String("sha-256".to_string()));
+    }
+
+    let alg = match parse_algorithm(algorithm) {
+        Ok(a) => a,
+        Err(e) => {
+            error!("{e}");
+            return;
+        }
+    };
+    let encoding_key = match build_encoding_key(alg, secret, private_key, None) {
+        Ok(k) => k,
+        Err(e) => {
+            error!("{e}");
+            return;
+        }
+    };
+
+    let jwt = match crypto::encode(alg, &claims, &Map::new(), &encoding_key) {
+        Ok(t) => t,
+        Err(e) => {
+            error!("Failed to encode JWT: {e}");
+            return;
+        }
+    };
+
+    let mut token = jwt;
+    for disclosure in &disclosures {
+        token.push('~');
+        token.push_str(disclosure);
+    }
+    token.push('~');
+    info!("{token}");
+}
+
+/// Builds a single Disclosure (`base64url([salt, name, value])`) and its digest.
+fn make_disclosure(rng: &mut impl RngCore, name: &str, value: &Value) -> (String, String) {
+    let mut salt_bytes = [0u8; 16];
+    rng.fill_bytes(&mut salt_bytes);
+    let salt = URL_SAFE_NO_PAD.encode(salt_bytes);
+
+    let disclosure = URL_SAFE_NO_PAD.encode(json!([salt, name, value]).to_string());
+    let digest = URL_SAFE_NO_PAD.encode(Sha256::digest(disclosure.as_bytes()));
+    (disclosure, digest)
+}
+
+/// Parses an SD-JWT, recomputes each disclosure's digest, and prints the
+/// header plus the reconstructed claim set with every matched disclosure
+/// filled in. Claims whose `_sd` digest has no matching disclosure are left
+/// out of the printed claim set; array elements without one keep their
+/// `{"...": digest}` placeholder.
+fn decode(token: &str) {
+    let mut segments = token.split('~');
+    let jwt = match segments.next() {
+        Some(jwt) if !jwt.is_empty() => jwt,
+        _ => {
+            error!("Malformed SD-JWT: missing JWT part");
+            return;
+        }
+    };
+
+    let jwt_parts: Vec<&str> = jwt.split('.').collect();
+    if jwt_parts.len() != 3 {
+        error!("Malformed JWT: expected header.payload.signature");
+        return;
+    }
+    let header = match decode_segment(jwt_parts[0]) {
+        Ok(v) => v,
+        Err(e) => {
+            error!("Invalid JWT header: {e}");
+            return;
+        }
+    };
+    let payload = match decode_segment(jwt_parts[1]) {
+        Ok(v) => v,
+        Err(e) => {
+            error!("Invalid JWT payload: {e}");
+            return;
+        }
+    };
+    info!("Header: {}", serde_json::to_string_pretty(&header).unwrap());
+
+    let rest: Vec<&str> = segments.collect();
+    let (disclosure_segments, has_key_binding) = split_disclosures(rest);
+
+    let mut by_digest: HashMap<String, (Option<String>, Value)> = HashMap::new();
+    for segment in &disclosure_segments {
+        match parse_disclosure(segment) {
+            Ok((name, value)) => {
+                let digest = URL_SAFE_NO_PAD.encode(Sha256::digest(segment.as_bytes()));
+                by_digest.insert(digest, (name, value));
+            }
+            Err(e) => error!("Skipping malformed disclosure: {e}"),
+        }
+    }
+
+    let revealed = reveal(&payload, &by_digest);
+    // A disclosure counts as matched when dropping it changes the
+    // reconstructed payload.
+    let matched = by_digest
+        .keys()
+        .filter(|digest| {
+            let mut without = by_digest.clone();
+            without.remove(*digest);
+            reveal(&payload, &without) != revealed
+        })
+        .count();
+    info!(
+        "Reconstructed payload ({} of {} disclosures matched): {}",
+        matched,
+        disclosure_segments.len(),
+        serde_json::to_string_pretty(&revealed).unwrap()
+    );
+    if has_key_binding {
+        info!("Key-binding JWT present (not verified)");
+    }
+}
+
+/// Separates the `~`-joined segments following the JWT into disclosures and
+/// whether a trailing key-binding JWT is present. The spec uses a trailing
+/// `~` to mean "no key-binding JWT"; a non-empty final segment would be one,
+/// but we don't verify it here.
+fn split_disclosures(segments: Vec<&str>) -> (Vec<&str>, bool) {
+    let mut rest = segments;
+    let has_key_binding = rest.last().is_some_and(|s| !s.is_empty());
+    if has_key_binding {
+        rest.pop();
+    }
+    (rest.into_iter().filter(|s| !s.is_empty()).collect(), has_key_binding)
+}
+
+/// Decodes a `[salt, name, value]` or `[salt, value]` (array-element) Disclosure.
+fn parse_disclosure(segment: &str) -> Result<(Option<String>, Value), String> {
+    let bytes = URL_SAFE_NO_PAD
+        .decode(segment)
+        .map_err(|e| format!("invalid base64url: {e}"))?;
+    let array: Vec<Value> =
+        serde_json::from_slice(&bytes).map_err(|e| format!("invalid disclosure JSON: {e}"))?;
+
+    match array.as_slice() {
+        [_salt, name, value] if name.is_string() => {
+            Ok((name.as_str().map(String::from), value.clone()))
+        }
+        [_salt, value] => Ok((None, value.clone())),
+        _ => Err(
+            "disclosure must be [salt, name, value] with a string name, or [salt, value]"
+                .to_string(),
+        ),
+    }
+}
+
+/// Recursively replaces `_sd` digests and `{"...": digest}` array placeholders
+/// with their matching disclosed claims. A digest with no matching disclosure
+/// is left undisclosed, which is normal and not an error.
+fn reveal(value: &Value, by_digest: &HashMap<String, (Option<String>, Value)>) -> Value {
+    match value {
+        Value::Object(map) => {
+            let mut out = Map::new();
+            for (key, v) in map {
+                if key == "_sd_alg" {
+                    continue;
+                }
+                if key == "_sd" {
+                    if let Value::Array(digests) = v {
+                        for digest in digests.iter().filter_map(Value::as_str) {
+                            if let Some((Some(name), claim_value)) = by_digest.get(digest) {
+                                out.insert(name.clone(), reveal(claim_value, by_digest));
+                            }
+                        }
+                    }
+                    continue;
+                }
+                out.insert(key.clone(), reveal(v, by_digest));
+            }
+            Value::Object(out)
+        }
+        Value::Array(items) => Value::Array(
+            items
+                .iter()
+                .map(|item| match item.get("...").and_then(Value::as_str) {
+                    Some(digest) => match by_digest.get(digest) {
+                        Some((_, claim_value)) => reveal(claim_value, by_digest),
+                        None => item.clone(),
+                    },
+                    None => reveal(item, by_digest),
+                })
+                .collect(),
+        ),
+        other => other.clone(),
+    }
+}
+
+fn decode_segment(segment: &str) -> Result<Value, String> {
+    let bytes = URL_SAFE_NO_PAD
+        .decode(segment)
+        .map_err(|e| format!("invalid base64url: {e}"))?;
+    serde_json::from_slice(&bytes).map_err(|e| format!("invalid JSON: {e}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn disclosure_round_trips() {
+        let mut rng = rand::thread_rng();
+        let value = Value::String("a@b.com".to_string());
+        let (disclosure, digest) = make_disclosure(&mut rng, "email", &value);
+
+        assert_eq!(parse_disclosure(&disclosure).unwrap(), (Some("email".to_string()), value));
+        assert_eq!(URL_SAFE_NO_PAD.encode(Sha256::digest(disclosure.as_bytes())), digest);
+    }
+
+    #[test]
+    fn reveal_resolves_nested_sd_objects() {
+        let mut rng = rand::thread_rng();
+        let mut by_digest = HashMap::new();
+
+        let city_value = json!("Stuttgart");
+        let (_, city_digest) = make_disclosure(&mut rng, "city", &city_value);
+        by_digest.insert(city_digest.clone(), (Some("city".to_string()), city_value));
+
+        let address_value = json!({ "_sd": [city_digest] });
+        let (_, address_digest) = make_disclosure(&mut rng, "address", &address_value);
+        by_digest.insert(address_digest.clone(), (Some("address".to_string()), address_value));
+
+        let payload = json!({ "sub": "123", "_sd": [address_digest] });
+        let revealed = reveal(&payload, &by_digest);
+
+        assert_eq!(revealed, json!({ "sub": "123", "address": { "city": "Stuttgart" } }));
+    }
+
+    #[test]
+    fn reveal_leaves_undisclosed_digests_alone() {
+        let payload = json!({ "sub": "123", "_sd": ["missing-digest"] });
+        let revealed = reveal(&payload, &HashMap::new());
+        assert_eq!(revealed, json!({ "sub": "123" }));
+    }
+
+    #[test]
+    fn reveal_resolves_array_element_placeholders() {
+        let disclosure = URL_SAFE_NO_PAD.encode(json!(["somesalt", "secret-item"]).to_string());
+        let digest = URL_SAFE_NO_PAD.encode(Sha256::digest(disclosure.as_bytes()));
+        assert_eq!(parse_disclosure(&disclosure).unwrap(), (None, json!("secret-item")));
+
+        let mut by_digest = HashMap::new();
+        by_digest.insert(digest.clone(), (None, json!("secret-item")));
+
+        let payload = json!({ "items": [{ "...": digest }, "plain"] });
+        let revealed = reveal(&payload, &by_digest);
+
+        assert_eq!(revealed, json!({ "items": ["secret-item", "plain"] }));
+    }
+
+    #[test]
+    fn split_disclosures_distinguishes_zero_disclosure_from_key_binding() {
+        // Trailing `~` only: no disclosures, no key-binding JWT.
+        assert_eq!(split_disclosures(vec![""]), (vec![], false));
+        // Disclosures present, still no key-binding JWT.
+        assert_eq!(split_disclosures(vec!["d1", ""]), (vec!["d1"], false));
+        // Non-empty trailing segment: a key-binding JWT is present.
+        assert_eq!(split_disclosures(vec!["d1", "kb-jwt"]), (vec!["d1"], true));
+        assert_eq!(split_disclosures(vec![]), (vec![], false));
+    }
+}
@@ -0,0 +1,229 @@
+use crate::cmd::crypto::{self, DecodingKey};
+use crate::cmd::jwk;
+use log::{error, info};
+use serde_json::Value;
+use std::fs;
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Verifies a JWT's signature using the given secret, PEM key, or JWK, then
+/// runs whichever registered-claim checks the caller asked for.
+#[allow(clippy::too_many_arguments)]
+pub fn execute(
+    token: &str,
+    secret: Option<&str>,
+    private_key: Option<&PathBuf>,
+    jwk_file: Option<&PathBuf>,
+    jwk_n: Option<&str>,
+    jwk_e: Option<&str>,
+    validate_exp: bool,
+    validate_nbf: bool,
+    validate_iat: bool,
+    aud: Option<&str>,
+    iss: Option<&str>,
+    sub: Option<&str>,
+    leeway: u64,
+) {
+    let alg = match crypto::peek_algorithm(token) {
+        Ok(a) => a,
+        Err(e) => {
+            error!("Failed to decode JWT header: {e}");
+            return;
+        }
+    };
+
+    let decoding_key = match build_decoding_key(alg, secret, private_key, jwk_file, jwk_n, jwk_e) {
+        Ok(k) => k,
+        Err(e) => {
+            error!("{e}");
+            return;
+        }
+    };
+
+    let claims = match crypto::decode(token, &decoding_key) {
+        Ok((_, claims)) => claims,
+        Err(e) => {
+            error!("Verification failed: {e}");
+            return;
+        }
+    };
+    info!("Signature valid");
+
+    let failures = check_claims(
+        &claims,
+        validate_exp,
+        validate_nbf,
+        validate_iat,
+        aud,
+        iss,
+        sub,
+        leeway,
+    );
+    if failures.is_empty() {
+        info!("All requested claim checks passed");
+    } else {
+        for failure in &failures {
+            error!("{failure}");
+        }
+    }
+
+    info!("{}", serde_json::to_string_pretty(&claims).unwrap());
+}
+
+/// Runs each requested registered-claim check against `claims`, returning one
+/// human-readable message per failing check (empty if everything passed).
+#[allow(clippy::too_many_arguments)]
+fn check_claims(
+    claims: &Value,
+    validate_exp: bool,
+    validate_nbf: bool,
+    validate_iat: bool,
+    aud: Option<&str>,
+    iss: Option<&str>,
+    sub: Option<&str>,
+    leeway: u64,
+) -> Vec<String> {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs() as i64;
+    let leeway = leeway as i64;
+    let mut failures = Vec::new();
+
+    if validate_exp {
+        match claims.get("exp").and_then(Value::as_i64) {
+            Some(exp) if now - leeway <= exp => {}
+            Some(exp) => failures.push(format!("exp: token expired at {exp} (now {now})")),
+            None => failures.push("exp: claim missing".to_string()),
+        }
+    }
+
+    if validate_nbf {
+        match claims.get("nbf").and_then(Value::as_i64) {
+            Some(nbf) if now + leeway >= nbf => {}
+            Some(nbf) => failures.push(format!("nbf: token not valid until {nbf} (now {now})")),
+            None => failures.push("nbf: claim missing".to_string()),
+        }
+    }
+
+    if validate_iat {
+        match claims.get("iat").and_then(Value::as_i64) {
+            Some(iat) if iat - leeway <= now => {}
+            Some(iat) => failures.push(format!("iat: issued-at {iat} is in the future (now {now})")),
+            None => failures.push("iat: claim missing".to_string()),
+        }
+    }
+
+    if let Some(expected) = aud {
+        let matches = match claims.get("aud") {
+            Some(Value::String(s)) => s == expected,
+            Some(Value::Array(values)) => values.iter().any(|v| v.as_str() == Some(expected)),
+            _ => false,
+        };
+        if !matches {
+            failures.push(format!("aud: expected `{expected}` not present in token"));
+        }
+    }
+
+    if let Some(expected) = iss {
+        match claims.get("iss").and_then(Value::as_str) {
+            Some(v) if v == expected => {}
+            Some(v) => failures.push(format!("iss: expected `{expected}`, got `{v}`")),
+            None => failures.push("iss: claim missing".to_string()),
+        }
+    }
+
+    if let Some(expected) = sub {
+        match claims.get("sub").and_then(Value::as_str) {
+            Some(v) if v == expected => {}
+            Some(v) => failures.push(format!("sub: expected `{expected}`, got `{v}`")),
+            None => failures.push("sub: claim missing".to_string()),
+        }
+    }
+
+    failures
+}
+
+/// Builds the verification key for the given algorithm from a secret, a PEM
+/// key, or a JWK (either `--jwk-n`/`--jwk-e` directly or a `--jwk` document).
+fn build_decoding_key(
+    alg: crypto::Algorithm,
+    secret: Option<&str>,
+    private_key: Option<&PathBuf>,
+    jwk_file: Option<&PathBuf>,
+    jwk_n: Option<&str>,
+    jwk_e: Option<&str>,
+) -> Result<DecodingKey, String> {
+    if alg.is_hmac() {
+        let secret = secret.ok_or("algorithm requires --secret")?;
+        return Ok(DecodingKey::from_secret(secret.as_bytes()));
+    }
+
+    if let (Some(n), Some(e)) = (jwk_n, jwk_e) {
+        return Ok(DecodingKey::from_rsa_components(n, e));
+    }
+
+    if let Some(path) = jwk_file {
+        let key = jwk::load(path)?;
+        return Ok(DecodingKey::from_rsa_components(&key.n, &key.e));
+    }
+
+    let path =
+        private_key.ok_or("algorithm requires --private-key, --jwk, or --jwk-n/--jwk-e")?;
+    let pem = fs::read(path).map_err(|e| format!("failed to read key: {e}"))?;
+    Ok(DecodingKey::from_pem(&pem))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn now() -> i64 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs() as i64
+    }
+
+    #[test]
+    fn exp_passes_within_leeway_but_fails_once_past_it() {
+        let claims = json!({ "exp": now() - 5 });
+        assert!(check_claims(&claims, true, false, false, None, None, None, 10).is_empty());
+        assert!(!check_claims(&claims, true, false, false, None, None, None, 2).is_empty());
+    }
+
+    #[test]
+    fn nbf_passes_within_leeway_but_fails_once_before_it() {
+        let claims = json!({ "nbf": now() + 5 });
+        assert!(check_claims(&claims, false, true, false, None, None, None, 10).is_empty());
+        assert!(!check_claims(&claims, false, true, false, None, None, None, 2).is_empty());
+    }
+
+    #[test]
+    fn iat_passes_within_leeway_but_fails_once_past_it() {
+        let claims = json!({ "iat": now() + 5 });
+        assert!(check_claims(&claims, false, false, true, None, None, None, 10).is_empty());
+        assert!(!check_claims(&claims, false, false, true, None, None, None, 2).is_empty());
+    }
+
+    #[test]
+    fn missing_time_claim_fails_when_validation_requested() {
+        let claims = json!({});
+        assert!(!check_claims(&claims, true, false, false, None, None, None, 0).is_empty());
+    }
+
+    #[test]
+    fn aud_matches_a_single_string_value() {
+        let claims = json!({ "aud": "my-service" });
+        assert!(check_claims(&claims, false, false, false, Some("my-service"), None, None, 0).is_empty());
+        assert!(!check_claims(&claims, false, false, false, Some("other"), None, None, 0).is_empty());
+    }
+
+    #[test]
+    fn aud_matches_any_element_of_an_array() {
+        let claims = json!({ "aud": ["a", "my-service", "b"] });
+        assert!(check_claims(&claims, false, false, false, Some("my-service"), None, None, 0).is_empty());
+        assert!(!check_claims(&claims, false, false, false, Some("missing"), None, None, 0).is_empty());
+    }
+}
@@ -0,0 +1,7 @@
+use crate::printing;
+
+/// Displays version information and project details.
+pub fn execute() {
+    println!("jwt-hack {}", printing::VERSION);
+    println!("https://github.com/hahwul/jwt-hack");
+}
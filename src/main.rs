@@ -0,0 +1,10 @@
+mod cmd;
+mod printing;
+
+fn main() {
+    if let Err(e) = printing::setup_logger() {
+        eprintln!("Failed to initialize logger: {e}");
+    }
+    printing::banner();
+    cmd::execute();
+}
@@ -0,0 +1,2 @@
+/// Crate version, surfaced by the `version` command and the CLI banner.
+pub const VERSION: &str = env!("CARGO_PKG_VERSION");